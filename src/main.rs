@@ -1,10 +1,13 @@
 mod summarizer;
 mod stats;
+mod progress;
 
 use anyhow::{Context, Result};
 use clap::Parser;
 use std::path::PathBuf;
 
+use summarizer::{OutputFormat, ShowColumn};
+
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Generate a summary of a repository or directory")]
 struct Args {
@@ -19,6 +22,33 @@ struct Args {
     /// Patterns to exclude (comma-separated glob patterns)
     #[arg(short, long)]
     exclude: Option<String>,
+
+    /// Output format: a human-readable text report, or a machine-readable
+    /// JSON report for feeding into other tools
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+
+    /// Include hidden (dot) files and directories; if both this and
+    /// --no-hidden are given, whichever comes last wins
+    #[arg(long, overrides_with = "no_hidden")]
+    hidden: bool,
+
+    /// Exclude hidden (dot) files and directories (default)
+    #[arg(long, overrides_with = "hidden")]
+    no_hidden: bool,
+
+    /// Don't respect .gitignore/.ignore rules when walking the directory
+    #[arg(long)]
+    no_ignore: bool,
+
+    /// Show a live progress counter on stderr while walking, reading, and
+    /// computing statistics
+    #[arg(long)]
+    progress: bool,
+
+    /// Annotate file entries in the tree output with a metadata column
+    #[arg(long, value_enum)]
+    show: Option<ShowColumn>,
 }
 
 fn main() -> Result<()> {
@@ -35,8 +65,17 @@ fn main() -> Result<()> {
 
     println!("Starting directory analysis...");
     
-    summarizer::generate_summary(&args.input_dir, &args.output_file, &exclude_patterns)
-        .context("Failed to generate summary")?;
+    summarizer::generate_summary(
+        &args.input_dir,
+        &args.output_file,
+        &exclude_patterns,
+        args.format,
+        args.hidden,
+        args.no_ignore,
+        args.progress,
+        args.show,
+    )
+    .context("Failed to generate summary")?;
     
     println!("Summary generated successfully at: {}", args.output_file.display());
     Ok(())
@@ -0,0 +1,77 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crossbeam_channel::{Receiver, Sender};
+
+/// The phase of summary generation a progress update belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressStage {
+    /// Walking the directory tree to discover files
+    Walking,
+    /// Reading and dumping file contents
+    Reading,
+    /// Collecting aggregate statistics
+    Statistics,
+}
+
+impl fmt::Display for ProgressStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            ProgressStage::Walking => "Walking",
+            ProgressStage::Reading => "Reading",
+            ProgressStage::Statistics => "Statistics",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// A single progress update, mirroring the shape czkawka reports during its
+/// own scans
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressData {
+    pub entries_checked: usize,
+    pub entries_to_check: usize,
+    pub current_stage: ProgressStage,
+}
+
+/// Send a progress update if a sender was given; --progress is off by
+/// default so callers can skip this entirely
+pub fn report(sender: Option<&Sender<ProgressData>>, checked: usize, total: usize, stage: ProgressStage) {
+    if let Some(sender) = sender {
+        let _ = sender.send(ProgressData {
+            entries_checked: checked,
+            entries_to_check: total,
+            current_stage: stage,
+        });
+    }
+}
+
+/// Render progress updates to stderr, overwriting the same line, until the
+/// channel is closed
+pub fn render(receiver: Receiver<ProgressData>) {
+    let mut stderr = io::stderr();
+
+    for update in receiver {
+        if update.entries_to_check > 0 {
+            let percent = (update.entries_checked * 100) / update.entries_to_check;
+            let _ = write!(
+                stderr,
+                "\r{:<10} [{:>3}%] {}/{}          ",
+                update.current_stage.to_string(),
+                percent,
+                update.entries_checked,
+                update.entries_to_check
+            );
+        } else {
+            let _ = write!(
+                stderr,
+                "\r{:<10} {} entries          ",
+                update.current_stage.to_string(),
+                update.entries_checked
+            );
+        }
+        let _ = stderr.flush();
+    }
+
+    let _ = writeln!(stderr);
+}
@@ -1,75 +1,140 @@
 use anyhow::Result;
+use crossbeam_channel::Sender;
 use rayon::prelude::*;
+use serde::Serialize;
+use siphasher::sip128::{Hasher128, SipHasher13};
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::hash::Hasher;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::progress::{self, ProgressData, ProgressStage};
+
+/// Number of leading bytes used for the partial-hash stage of duplicate
+/// detection.
+const PARTIAL_HASH_BYTES: usize = 4096;
 
 /// Stores statistics about analyzed files
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct FileStats {
     /// Total number of files
     pub total_files: usize,
-    
+
     /// Total number of directories
     pub total_directories: usize,
-    
+
     /// Total lines of code
     pub total_lines: usize,
-    
+
     /// Count of files by extension
     pub extension_counts: HashMap<String, usize>,
-    
+
     /// Count of lines by extension
     pub extension_lines: HashMap<String, usize>,
+
+    /// Groups of files with identical content, each inner vector listing
+    /// the paths that share that content
+    pub duplicates: Vec<Vec<PathBuf>>,
+
+    /// Per-directory rollups of (relative path, total lines, total bytes),
+    /// computed bottom-up over the directory tree
+    pub directory_sizes: Vec<(PathBuf, usize, u64)>,
+
+    /// Per-file metadata (size, modification time, permissions) collected
+    /// during traversal, borrowing exa's column model
+    pub file_metadata: Vec<FileMetadata>,
+}
+
+/// Metadata about a single file, used for the "largest files" and
+/// "recently modified files" statistics sections
+#[derive(Debug, Clone, Serialize)]
+pub struct FileMetadata {
+    pub path: PathBuf,
+    pub size: u64,
+    /// Last modification time, in seconds since the Unix epoch
+    pub modified_unix_secs: u64,
+    /// Unix permission bits (e.g. 0o644), unavailable on non-Unix platforms
+    pub permissions: Option<u32>,
 }
 
-/// Collect statistics about the given files
-pub fn collect_stats(file_paths: &[PathBuf]) -> Result<FileStats> {
+/// Count the number of lines in every given file in parallel, reporting
+/// progress as each file is read. The result is keyed by path so callers
+/// that need per-file line counts (such as the in-memory directory tree)
+/// can reuse this single pass instead of reading each file again.
+pub fn count_lines_for_files(
+    file_paths: &[PathBuf],
+    progress_sender: Option<&Sender<ProgressData>>,
+) -> HashMap<PathBuf, usize> {
+    let total = file_paths.len();
+    let checked = AtomicUsize::new(0);
+
+    file_paths
+        .par_iter()
+        .filter_map(|path| {
+            let line_count = match count_lines(path) {
+                Ok(line_count) => line_count,
+                Err(err) => {
+                    eprintln!("Warning: Failed to count lines in {}: {}", path.display(), err);
+                    return None;
+                }
+            };
+
+            let done = checked.fetch_add(1, Ordering::Relaxed) + 1;
+            progress::report(progress_sender, done, total, ProgressStage::Reading);
+
+            Some((path.clone(), line_count))
+        })
+        .collect()
+}
+
+/// Collect statistics about the given files. `file_paths` covers the
+/// non-binary files whose lines were counted; `all_file_paths` covers every
+/// discovered file, binary or not, for the metadata and duplicate-detection
+/// sections that have no reason to skip binaries.
+pub fn collect_stats(
+    file_paths: &[PathBuf],
+    all_file_paths: &[PathBuf],
+    line_counts: &HashMap<PathBuf, usize>,
+    progress_sender: Option<&Sender<ProgressData>>,
+) -> Result<FileStats> {
     let mut stats = FileStats::default();
-    
+
     // Count files
     stats.total_files = file_paths.len();
-    
+
     // Count unique directories
     let directories: std::collections::HashSet<_> = file_paths
         .iter()
         .filter_map(|path| path.parent().map(|p| p.to_path_buf()))
         .collect();
     stats.total_directories = directories.len();
-    
-    // Process files in parallel to collect extension and line counts
-    let results: Vec<Result<(String, usize)>> = file_paths
-        .par_iter()
-        .map(|path| -> Result<(String, usize)> {
-            // Get file extension
+
+    // Roll the already-computed line counts up into per-extension totals
+    let total = file_paths.len();
+    for (checked, path) in file_paths.iter().enumerate() {
+        if let Some(&line_count) = line_counts.get(path) {
             let extension = path
                 .extension()
                 .and_then(|ext| ext.to_str())
                 .unwrap_or("")
                 .to_string();
-            
-            // Count lines
-            let line_count = count_lines(path)?;
-            
-            Ok((extension, line_count))
-        })
-        .collect();
-    
-    // Process results
-    for result in results {
-        match result {
-            Ok((extension, line_count)) => {
-                *stats.extension_counts.entry(extension.clone()).or_insert(0) += 1;
-                *stats.extension_lines.entry(extension).or_insert(0) += line_count;
-                stats.total_lines += line_count;
-            },
-            Err(err) => {
-                eprintln!("Warning: Failed to process file statistics: {}", err);
-            }
+
+            *stats.extension_counts.entry(extension.clone()).or_insert(0) += 1;
+            *stats.extension_lines.entry(extension).or_insert(0) += line_count;
+            stats.total_lines += line_count;
         }
+
+        progress::report(progress_sender, checked + 1, total, ProgressStage::Statistics);
     }
-    
+
+    stats.duplicates = find_duplicate_groups(all_file_paths);
+    stats.file_metadata = all_file_paths
+        .par_iter()
+        .filter_map(|path| collect_file_metadata(path).ok())
+        .collect();
+
     Ok(stats)
 }
 
@@ -79,3 +144,111 @@ fn count_lines(path: &Path) -> Result<usize> {
     let reader = BufReader::new(file);
     Ok(reader.lines().count())
 }
+
+/// Collect size, modification time, and (on Unix) permission metadata for a
+/// single file. Shared by the aggregate statistics and the in-memory
+/// directory tree so the two don't each carry their own copy of this
+/// extraction logic.
+pub(crate) fn collect_file_metadata(path: &Path) -> Result<FileMetadata> {
+    let metadata = std::fs::metadata(path)?;
+
+    let modified_unix_secs = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    Ok(FileMetadata {
+        path: path.to_path_buf(),
+        size: metadata.len(),
+        modified_unix_secs,
+        permissions: unix_permissions(&metadata),
+    })
+}
+
+#[cfg(unix)]
+fn unix_permissions(metadata: &std::fs::Metadata) -> Option<u32> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.mode() & 0o7777)
+}
+
+#[cfg(not(unix))]
+fn unix_permissions(_metadata: &std::fs::Metadata) -> Option<u32> {
+    None
+}
+
+/// Group files with identical content using the three-stage approach from
+/// ddh: bucket by file size (files of differing size can't be equal), then
+/// narrow each size bucket by a partial hash of the first few KiB, and
+/// finally confirm with a full-content hash for anything still colliding.
+/// Each stage runs in parallel with rayon, the same way `collect_stats`
+/// processes files.
+fn find_duplicate_groups(file_paths: &[PathBuf]) -> Vec<Vec<PathBuf>> {
+    let sizes: Vec<(u64, PathBuf)> = file_paths
+        .par_iter()
+        .filter_map(|path| {
+            std::fs::metadata(path)
+                .ok()
+                .map(|metadata| (metadata.len(), path.clone()))
+        })
+        .collect();
+
+    let mut size_buckets: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    for (size, path) in sizes {
+        size_buckets.entry(size).or_default().push(path);
+    }
+
+    size_buckets
+        .into_par_iter()
+        // Empty files all share a size (and hash) but have nothing to
+        // reclaim, so they'd otherwise be reported as pure noise
+        .filter(|(size, paths)| *size > 0 && paths.len() > 1)
+        .flat_map(|(_, paths)| bucket_by_hash(paths, hash_file_prefix))
+        .flat_map(|paths| bucket_by_hash(paths, hash_file_full))
+        .collect()
+}
+
+/// Split a set of same-size files into groups sharing the same hash,
+/// discarding groups of one (i.e. files whose hash was unique).
+fn bucket_by_hash(
+    paths: Vec<PathBuf>,
+    hash_fn: impl Fn(&Path) -> Result<u128> + Sync,
+) -> Vec<Vec<PathBuf>> {
+    let hashes: Vec<(u128, PathBuf)> = paths
+        .into_par_iter()
+        .filter_map(|path| hash_fn(&path).ok().map(|hash| (hash, path)))
+        .collect();
+
+    let mut buckets: HashMap<u128, Vec<PathBuf>> = HashMap::new();
+    for (hash, path) in hashes {
+        buckets.entry(hash).or_default().push(path);
+    }
+
+    buckets.into_values().filter(|group| group.len() > 1).collect()
+}
+
+/// Hash the first `PARTIAL_HASH_BYTES` of a file with 128-bit SipHash.
+fn hash_file_prefix(path: &Path) -> Result<u128> {
+    let mut file = File::open(path)?;
+    let mut buffer = vec![0u8; PARTIAL_HASH_BYTES];
+    let bytes_read = file.read(&mut buffer)?;
+    let mut hasher = SipHasher13::new();
+    hasher.write(&buffer[..bytes_read]);
+    Ok(hasher.finish128().as_u128())
+}
+
+/// Hash the full contents of a file with 128-bit SipHash.
+fn hash_file_full(path: &Path) -> Result<u128> {
+    let mut file = File::open(path)?;
+    let mut hasher = SipHasher13::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        let bytes_read = file.read(&mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        hasher.write(&buffer[..bytes_read]);
+    }
+    Ok(hasher.finish128().as_u128())
+}
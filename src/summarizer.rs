@@ -1,20 +1,48 @@
 use anyhow::{Context, Result};
-use ignore::{DirEntry, Walk, WalkBuilder};
+use crossbeam_channel::Sender;
+use ignore::{Walk, WalkBuilder};
 use ptree::{Style, TreeBuilder, print_tree};
-use rayon::prelude::*;
-use std::collections::HashSet;
+use serde::Serialize;
+use std::collections::HashMap;
 use std::fs::{File, read_link};
 use std::io::{self, Write as IoWrite};
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 
-use crate::stats::{FileStats, collect_stats};
+use crate::progress::{self, ProgressData, ProgressStage};
+use crate::stats::{self, FileMetadata, FileStats, collect_file_metadata, collect_stats};
+
+/// The shape of the generated summary report
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum OutputFormat {
+    /// A human-readable, line-numbered text dump
+    Text,
+    /// A machine-readable JSON report for feeding into other tools
+    Json,
+}
+
+/// A metadata column to annotate file entries with in the tree output,
+/// borrowing exa's column model
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum ShowColumn {
+    /// File size
+    Size,
+    /// Last modification time
+    Mtime,
+    /// Unix permission bits
+    Perms,
+}
 
 /// Generate a summary of the given directory
 pub fn generate_summary(
     input_dir: &Path,
     output_file: &Path,
     exclude_patterns: &[String],
+    format: OutputFormat,
+    show_hidden: bool,
+    no_ignore: bool,
+    show_progress: bool,
+    show_column: Option<ShowColumn>,
 ) -> Result<()> {
     // Ensure the input directory exists
     if !input_dir.exists() {
@@ -25,48 +53,131 @@ pub fn generate_summary(
     let file = File::create(output_file).context("Failed to create output file")?;
     let mut writer = BufWriter::new(file);
 
-    // Build the directory tree and collect file information
-    let mut tree = ptree::TreeBuilder::new(input_dir.to_string_lossy().to_string());
-    let mut file_paths = Vec::new();
     let input_dir_canonicalized = input_dir
         .canonicalize()
         .unwrap_or_else(|_| input_dir.to_path_buf());
 
-    // Collect all entries while building the tree
-    let walker = build_walker(input_dir, exclude_patterns);
-    process_entries(walker, &input_dir_canonicalized, &mut tree, &mut file_paths)?;
+    // Set up a progress channel and renderer thread if --progress was given
+    let (progress_sender, progress_handle) = if show_progress {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let handle = std::thread::spawn(move || progress::render(receiver));
+        (Some(sender), Some(handle))
+    } else {
+        (None, None)
+    };
+
+    // Walk the directory, collecting file paths (text and binary alike) and
+    // a flat list of entries
+    let mut file_paths = Vec::new();
+    let mut all_file_paths = Vec::new();
+    // Walk from the canonicalized path, not the raw `input_dir`, so entry
+    // paths always share a prefix with `input_dir_canonicalized` below --
+    // otherwise `strip_prefix` fails for any input that isn't already in
+    // canonical form (a relative path, a trailing slash, a symlink), and
+    // `line_counts` keyed off the un-stripped path never matches the path
+    // `build_directory_tree` looks it up with
+    let walker = build_walker(&input_dir_canonicalized, exclude_patterns, show_hidden, no_ignore);
+    let entries = process_entries(
+        walker,
+        &input_dir_canonicalized,
+        &mut file_paths,
+        &mut all_file_paths,
+        progress_sender.as_ref(),
+    )?;
+
+    // Count each non-binary file's lines once, up front, so both the
+    // directory tree and the aggregate statistics can reuse the result
+    // instead of each reading every file again
+    let line_counts = stats::count_lines_for_files(&file_paths, progress_sender.as_ref());
+
+    // Build an in-memory directory tree so each directory's total line count
+    // and byte size can be rolled up bottom-up from its descendants
+    let dir_tree = build_directory_tree(&input_dir_canonicalized, &entries, &line_counts)?;
+
+    // In text mode, the tree is rendered and each file's contents dumped
+    // while still in the Reading stage; in JSON mode there's no content
+    // dump, so Reading is carried entirely by the line-counting pass above.
+    // Either way, statistics (the Statistics stage) are only collected once
+    // all reading is done, so progress moves forward through Walking,
+    // Reading, and Statistics without doubling back.
+    if let OutputFormat::Text = format {
+        let tree_builder =
+            build_ptree(input_dir.to_string_lossy().to_string(), &dir_tree, show_column);
+        let mut tree_string = Vec::new();
+        print_tree_to_writer(&mut tree_string, &tree_builder.build())?;
+        writeln!(writer, "{}", String::from_utf8_lossy(&tree_string))
+            .context("Failed to write tree structure")?;
+        writeln!(writer).context("Failed to write newline")?;
 
-    // Write the tree structure to a string
-    let mut tree_string = Vec::new();
-    print_tree_to_writer(&mut tree_string, &tree.build())?;
-    writeln!(writer, "{}", String::from_utf8_lossy(&tree_string))
-        .context("Failed to write tree structure")?;
-    writeln!(writer).context("Failed to write newline")?;
+        process_file_contents(&mut writer, &file_paths, progress_sender.as_ref())?;
+    }
 
-    // Process and write file contents
-    process_file_contents(&mut writer, &file_paths)?;
+    // Collect statistics, then merge in the directory rollups
+    let mut stats = collect_stats(&file_paths, &all_file_paths, &line_counts, progress_sender.as_ref())?;
+    let mut directory_sizes = Vec::new();
+    collect_directory_sizes(&dir_tree, "", &mut directory_sizes);
+    directory_sizes.sort_by(|a, b| b.2.cmp(&a.2));
+    stats.directory_sizes = directory_sizes
+        .into_iter()
+        .map(|(path, lines, bytes)| (PathBuf::from(path), lines, bytes))
+        .collect();
+
+    match format {
+        OutputFormat::Text => {
+            write_statistics(&mut writer, &stats)?;
+        }
+        OutputFormat::Json => {
+            let mut files = Vec::new();
+            collect_file_entries(&dir_tree, "", &mut files);
+
+            // The per-file line counts here and `stats.total_lines` are
+            // both derived from the same `line_counts` map, so they must
+            // always add up; if
+            // they don't, the tree was built from paths that didn't line up
+            // with `line_counts`
+            debug_assert_eq!(
+                files.iter().map(|entry| entry.lines).sum::<usize>(),
+                stats.total_lines,
+                "JSON file line counts must add up to the aggregate total_lines"
+            );
 
-    // Collect and write statistics
-    let stats = collect_stats(&file_paths)?;
-    write_statistics(&mut writer, &stats)?;
+            let report = SummaryReport {
+                tree: node_to_tree_entry(input_dir.to_string_lossy().to_string(), &dir_tree),
+                files,
+                stats,
+            };
+            serde_json::to_writer_pretty(&mut writer, &report)
+                .context("Failed to write JSON summary")?;
+            writeln!(writer).context("Failed to write newline")?;
+        }
+    }
 
     writer.flush().context("Failed to flush output")?;
 
+    // Dropping the sender closes the channel so the renderer thread exits
+    drop(progress_sender);
+    if let Some(handle) = progress_handle {
+        let _ = handle.join();
+    }
+
     Ok(())
 }
 
 /// Build a walker with excluded patterns
-fn build_walker(input_dir: &Path, exclude_patterns: &[String]) -> Walk {
+fn build_walker(
+    input_dir: &Path,
+    exclude_patterns: &[String],
+    show_hidden: bool,
+    no_ignore: bool,
+) -> Walk {
     let mut builder = WalkBuilder::new(input_dir);
 
-    // Add standard excludes
-    builder.filter_entry(|entry| {
-        !entry
-            .file_name()
-            .to_str()
-            .map(|s| s.starts_with('.') && s != "." && s != "..")
-            .unwrap_or(false)
-    });
+    // Hidden (dot) files are skipped unless --hidden was passed
+    builder.hidden(!show_hidden);
+
+    // .gitignore/.ignore rules are honored unless --no-ignore was passed
+    builder.git_ignore(!no_ignore);
+    builder.ignore(!no_ignore);
 
     // Add custom exclude patterns
     for pattern in exclude_patterns {
@@ -87,18 +198,28 @@ fn build_walker(input_dir: &Path, exclude_patterns: &[String]) -> Walk {
     builder.build()
 }
 
-/// Process entries from the walker, building the tree and collecting file paths
+/// A single filesystem entry discovered while walking the input directory,
+/// with its path kept relative to the input directory
+enum RawEntry {
+    File { path: PathBuf, is_binary: bool },
+    Dir(PathBuf),
+    Symlink { path: PathBuf, target: String },
+}
+
+/// Walk the given entries, collecting file paths for content processing, a
+/// list of every discovered file path (binary or not, for metadata and
+/// duplicate detection), and a flat list of raw entries used to build the
+/// in-memory directory tree
 fn process_entries(
     walker: Walk,
     base_dir: &Path,
-    tree_builder: &mut ptree::TreeBuilder,
     file_paths: &mut Vec<PathBuf>,
-) -> Result<()> {
-    // Keep track of directories we've added to the tree
-    let mut added_dirs = HashSet::new();
-    added_dirs.insert(base_dir.to_path_buf());
+    all_file_paths: &mut Vec<PathBuf>,
+    progress_sender: Option<&Sender<ProgressData>>,
+) -> Result<Vec<RawEntry>> {
+    let mut entries = Vec::new();
+    let mut checked = 0;
 
-    // Process each entry
     for result in walker {
         let entry = match result {
             Ok(entry) => entry,
@@ -108,6 +229,11 @@ fn process_entries(
             }
         };
 
+        // The total entry count isn't known until the walk finishes, so
+        // this stage reports a running count rather than a percentage
+        checked += 1;
+        progress::report(progress_sender, checked, 0, ProgressStage::Walking);
+
         let path = entry.path();
 
         // Skip the root directory itself
@@ -115,116 +241,377 @@ fn process_entries(
             continue;
         }
 
-        // Handle the entry based on its type
+        let rel_path = path.strip_prefix(base_dir).unwrap_or(path).to_path_buf();
+
         if entry.file_type().map_or(false, |ft| ft.is_file()) {
-            // Only include the file if it's not binary
-            if !is_binary_file(path)? {
-                // Add file to tree
-                add_path_to_tree(path, base_dir, tree_builder, &mut added_dirs);
+            let is_binary = is_binary_file(path)?;
+            // Binary files are still reported in the tree and JSON output,
+            // and still counted for metadata and duplicate detection, but
+            // their contents are never dumped or line-counted
+            all_file_paths.push(path.to_path_buf());
+            if !is_binary {
                 file_paths.push(path.to_path_buf());
             }
+            entries.push(RawEntry::File {
+                path: rel_path,
+                is_binary,
+            });
         } else if entry.file_type().map_or(false, |ft| ft.is_dir()) {
-            // Add directory to tree
-            add_path_to_tree(path, base_dir, tree_builder, &mut added_dirs);
+            entries.push(RawEntry::Dir(rel_path));
         } else if entry.file_type().map_or(false, |ft| ft.is_symlink()) {
-            // Handle symlink - add to tree with target information
             let link_target = match read_link(path) {
-                Ok(target) => format!(" -> {}", target.display()),
-                Err(_) => " -> [unreadable link]".to_string(),
+                Ok(target) => target.display().to_string(),
+                Err(_) => "[unreadable link]".to_string(),
             };
 
-            let rel_path = path.strip_prefix(base_dir).unwrap_or(path);
-            let parent_path = match rel_path.parent() {
-                Some(parent) if !parent.as_os_str().is_empty() => {
-                    add_dir_to_tree(parent, base_dir, tree_builder, &mut added_dirs);
-                    parent
-                }
-                _ => Path::new(""),
-            };
+            entries.push(RawEntry::Symlink {
+                path: rel_path,
+                target: link_target,
+            });
+        }
+    }
 
-            // Add the symlink with its target noted
-            let item_name = format!(
-                "{}{}",
-                path.file_name().unwrap_or_default().to_string_lossy(),
-                link_target
-            );
+    Ok(entries)
+}
 
-            if !parent_path.as_os_str().is_empty() {
-                tree_builder.begin_child(parent_path.to_string_lossy().to_string());
-                tree_builder.add_empty_child(item_name);
-                tree_builder.end_child();
-            } else {
-                tree_builder.begin_child(base_dir.to_string_lossy().to_string());
-                tree_builder.add_empty_child(item_name);
-                tree_builder.end_child();
+/// A node in the in-memory directory tree, used to roll up each directory's
+/// total line count and byte size from its descendants
+enum Node {
+    Dir(HashMap<String, Node>),
+    File {
+        lines: usize,
+        bytes: u64,
+        is_binary: bool,
+        modified_unix_secs: u64,
+        permissions: Option<u32>,
+    },
+    Symlink { target: String },
+}
+
+impl Node {
+    fn new_dir() -> Self {
+        Node::Dir(HashMap::new())
+    }
+
+    /// This node's aggregate (lines, bytes); for a directory this is the
+    /// sum of its descendants, computed bottom-up
+    fn size(&self) -> (usize, u64) {
+        match self {
+            Node::File { lines, bytes, .. } => (*lines, *bytes),
+            Node::Symlink { .. } => (0, 0),
+            Node::Dir(children) => children.values().fold((0, 0), |(lines, bytes), child| {
+                let (child_lines, child_bytes) = child.size();
+                (lines + child_lines, bytes + child_bytes)
+            }),
+        }
+    }
+
+    /// Insert a leaf node at the given path components, creating any
+    /// missing intermediate directories along the way
+    fn insert(&mut self, components: &[&str], leaf: Node) {
+        let Node::Dir(children) = self else {
+            return;
+        };
+        match components {
+            [] => {}
+            [name] => {
+                children.insert((*name).to_string(), leaf);
+            }
+            [name, rest @ ..] => {
+                children
+                    .entry((*name).to_string())
+                    .or_insert_with(Node::new_dir)
+                    .insert(rest, leaf);
             }
         }
     }
 
-    Ok(())
+    /// Ensure a directory exists at the given path components, creating any
+    /// missing intermediate directories along the way
+    fn ensure_dir(&mut self, components: &[&str]) {
+        let Node::Dir(children) = self else {
+            return;
+        };
+        if let [name, rest @ ..] = components {
+            children
+                .entry((*name).to_string())
+                .or_insert_with(Node::new_dir)
+                .ensure_dir(rest);
+        }
+    }
 }
 
-/// Add a path to the tree, ensuring all parent directories exist
-fn add_path_to_tree(
-    path: &Path,
-    base_dir: &Path,
-    tree_builder: &mut ptree::TreeBuilder,
-    added_dirs: &mut HashSet<PathBuf>,
-) {
-    let rel_path = path.strip_prefix(base_dir).unwrap_or(path);
+/// Split a relative path into its string components
+fn path_components(path: &Path) -> Vec<&str> {
+    path.components()
+        .filter_map(|component| component.as_os_str().to_str())
+        .collect()
+}
 
-    if path.is_dir() {
-        add_dir_to_tree(rel_path, base_dir, tree_builder, added_dirs);
-    } else {
-        let parent_path = match rel_path.parent() {
-            Some(parent) if !parent.as_os_str().is_empty() => {
-                add_dir_to_tree(parent, base_dir, tree_builder, added_dirs);
-                parent.to_string_lossy().to_string()
+/// Build the in-memory directory tree from the entries collected while
+/// walking. Line counts are taken from the already-computed `line_counts`
+/// map rather than reading each file again.
+fn build_directory_tree(
+    base_dir: &Path,
+    entries: &[RawEntry],
+    line_counts: &HashMap<PathBuf, usize>,
+) -> Result<Node> {
+    let mut root = Node::new_dir();
+
+    for entry in entries {
+        match entry {
+            RawEntry::Dir(rel_path) => {
+                root.ensure_dir(&path_components(rel_path));
             }
-            _ => base_dir.to_string_lossy().to_string(),
-        };
+            RawEntry::File { path: rel_path, is_binary } => {
+                let full_path = base_dir.join(rel_path);
+                let lines = line_counts.get(&full_path).copied().unwrap_or(0);
+                // A file that goes missing or unreadable between the walk
+                // and here shouldn't abort the whole summary -- fall back to
+                // zeroed metadata, the same tolerance `collect_stats` gives
+                // unreadable files via `filter_map(... .ok())`
+                let metadata = collect_file_metadata(&full_path).unwrap_or_else(|err| {
+                    eprintln!(
+                        "Warning: Failed to collect metadata for {}: {}",
+                        full_path.display(),
+                        err
+                    );
+                    FileMetadata {
+                        path: full_path.clone(),
+                        size: 0,
+                        modified_unix_secs: 0,
+                        permissions: None,
+                    }
+                });
+                root.insert(
+                    &path_components(rel_path),
+                    Node::File {
+                        lines,
+                        bytes: metadata.size,
+                        is_binary: *is_binary,
+                        modified_unix_secs: metadata.modified_unix_secs,
+                        permissions: metadata.permissions,
+                    },
+                );
+            }
+            RawEntry::Symlink { path, target } => {
+                root.insert(
+                    &path_components(path),
+                    Node::Symlink {
+                        target: target.clone(),
+                    },
+                );
+            }
+        }
+    }
 
-        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
-        tree_builder.begin_child(parent_path);
-        tree_builder.add_empty_child(file_name.to_string());
-        tree_builder.end_child();
+    Ok(root)
+}
+
+/// Build the `ptree` tree used for display, annotating every directory
+/// label with its rolled-up line count and byte size, and every file label
+/// with the `--show` column if one was requested
+fn build_ptree(root_label: String, root: &Node, show_column: Option<ShowColumn>) -> TreeBuilder {
+    let mut builder = TreeBuilder::new(root_label);
+    if let Node::Dir(children) = root {
+        add_children_to_ptree(&mut builder, children, show_column);
     }
+    builder
 }
 
-/// Add a directory and all its parents to the tree
-fn add_dir_to_tree(
-    dir_path: &Path,
-    base_dir: &Path,
-    tree_builder: &mut ptree::TreeBuilder,
-    added_dirs: &mut HashSet<PathBuf>,
+fn add_children_to_ptree(
+    builder: &mut TreeBuilder,
+    children: &HashMap<String, Node>,
+    show_column: Option<ShowColumn>,
 ) {
-    let mut current = PathBuf::new();
-    let full_path = base_dir.join(dir_path);
+    let mut names: Vec<&String> = children.keys().collect();
+    names.sort();
+
+    for name in names {
+        let child = &children[name];
+        match child {
+            Node::Dir(grandchildren) => {
+                let (lines, bytes) = child.size();
+                let label = format!("{}/ ({} lines, {})", name, lines, format_bytes(bytes));
+                builder.begin_child(label);
+                add_children_to_ptree(builder, grandchildren, show_column);
+                builder.end_child();
+            }
+            Node::File {
+                is_binary,
+                bytes,
+                modified_unix_secs,
+                permissions,
+                ..
+            } => {
+                let mut label = name.clone();
+                if let Some(column) = show_column {
+                    label = format!("{}  {}", label, format_show_column(column, *bytes, *modified_unix_secs, *permissions));
+                }
+                if *is_binary {
+                    label = format!("{} [binary]", label);
+                }
+                builder.add_empty_child(label);
+            }
+            Node::Symlink { target } => {
+                builder.add_empty_child(format!("{} -> {}", name, target));
+            }
+        }
+    }
+}
 
-    if added_dirs.contains(&full_path) {
-        return;
+/// Format the value of a single `--show` column for a file entry
+fn format_show_column(
+    column: ShowColumn,
+    bytes: u64,
+    modified_unix_secs: u64,
+    permissions: Option<u32>,
+) -> String {
+    match column {
+        ShowColumn::Size => format_bytes(bytes),
+        ShowColumn::Mtime => format!("mtime:{}", modified_unix_secs),
+        ShowColumn::Perms => match permissions {
+            Some(mode) => format!("{:o}", mode),
+            None => "n/a".to_string(),
+        },
     }
+}
 
-    for component in dir_path.components() {
-        let prev_path = current.clone();
-        current.push(component);
-
-        let full_current = base_dir.join(&current);
-        if !added_dirs.contains(&full_current) {
-            let component_name = component.as_os_str().to_string_lossy();
-            if prev_path.as_os_str().is_empty() {
-                tree_builder.begin_child(base_dir.to_string_lossy().to_string());
-                tree_builder.add_empty_child(component_name.to_string());
-                tree_builder.end_child();
+/// Recursively collect (relative path, lines, bytes) for every directory in
+/// the tree
+fn collect_directory_sizes(node: &Node, prefix: &str, out: &mut Vec<(String, usize, u64)>) {
+    if let Node::Dir(children) = node {
+        for (name, child) in children {
+            if let Node::Dir(_) = child {
+                let path = if prefix.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}/{}", prefix, name)
+                };
+                let (lines, bytes) = child.size();
+                out.push((path.clone(), lines, bytes));
+                collect_directory_sizes(child, &path, out);
+            }
+        }
+    }
+}
+
+/// A serializable description of a single file entry, used by the JSON
+/// report's flat `files` list
+#[derive(Serialize)]
+struct FileEntry {
+    path: PathBuf,
+    extension: String,
+    lines: usize,
+    is_binary: bool,
+}
+
+/// Recursively collect a flat list of file entries for the JSON report
+fn collect_file_entries(node: &Node, prefix: &str, out: &mut Vec<FileEntry>) {
+    if let Node::Dir(children) = node {
+        for (name, child) in children {
+            let path = if prefix.is_empty() {
+                name.clone()
             } else {
-                let prev_str = prev_path.to_string_lossy().to_string();
-                tree_builder.begin_child(prev_str);
-                tree_builder.add_empty_child(component_name.to_string());
-                tree_builder.end_child();
+                format!("{}/{}", prefix, name)
+            };
+            match child {
+                Node::Dir(_) => collect_file_entries(child, &path, out),
+                Node::File { lines, is_binary, .. } => {
+                    let extension = Path::new(name)
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .unwrap_or("")
+                        .to_string();
+                    out.push(FileEntry {
+                        path: PathBuf::from(path),
+                        extension,
+                        lines: *lines,
+                        is_binary: *is_binary,
+                    });
+                }
+                Node::Symlink { .. } => {}
             }
+        }
+    }
+}
 
-            added_dirs.insert(full_current);
+/// A serializable description of the directory tree, used by the JSON
+/// report's `tree` field
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TreeEntry {
+    Directory {
+        name: String,
+        lines: usize,
+        bytes: u64,
+        children: Vec<TreeEntry>,
+    },
+    File {
+        name: String,
+        lines: usize,
+        bytes: u64,
+        is_binary: bool,
+    },
+    Symlink {
+        name: String,
+        target: String,
+    },
+}
+
+/// Convert an in-memory directory node into its serializable JSON form
+fn node_to_tree_entry(name: String, node: &Node) -> TreeEntry {
+    match node {
+        Node::Dir(children) => {
+            let (lines, bytes) = node.size();
+            let mut names: Vec<&String> = children.keys().collect();
+            names.sort();
+            let children = names
+                .into_iter()
+                .map(|child_name| node_to_tree_entry(child_name.clone(), &children[child_name]))
+                .collect();
+            TreeEntry::Directory {
+                name,
+                lines,
+                bytes,
+                children,
+            }
         }
+        Node::File { lines, bytes, is_binary, .. } => TreeEntry::File {
+            name,
+            lines: *lines,
+            bytes: *bytes,
+            is_binary: *is_binary,
+        },
+        Node::Symlink { target } => TreeEntry::Symlink {
+            name,
+            target: target.clone(),
+        },
+    }
+}
+
+/// The top-level JSON report, combining the directory tree, a flat file
+/// list, and the aggregate statistics
+#[derive(Serialize)]
+struct SummaryReport {
+    tree: TreeEntry,
+    files: Vec<FileEntry>,
+    stats: FileStats,
+}
+
+/// Format a byte count using binary (KiB/MiB/GiB) units
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
     }
 }
 
@@ -248,8 +635,16 @@ fn is_binary_file(path: &Path) -> Result<bool> {
 }
 
 /// Process and write the contents of each file
-fn process_file_contents(writer: &mut impl Write, file_paths: &[PathBuf]) -> Result<()> {
-    for path in file_paths {
+fn process_file_contents(
+    writer: &mut impl Write,
+    file_paths: &[PathBuf],
+    progress_sender: Option<&Sender<ProgressData>>,
+) -> Result<()> {
+    let total = file_paths.len();
+
+    for (checked, path) in file_paths.iter().enumerate() {
+        progress::report(progress_sender, checked + 1, total, ProgressStage::Reading);
+
         // Create a separator line
         let separator = "-".repeat(80);
         writeln!(writer, "{}:", path.display()).context("Failed to write path")?;
@@ -282,6 +677,13 @@ fn print_tree_to_writer(writer: &mut impl Write, tree: &ptree::item::StringItem)
     Ok(())
 }
 
+/// Number of entries to show in the "largest directories" section
+const TOP_DIRECTORIES: usize = 10;
+
+/// Number of entries to show in the "largest files" and "recently
+/// modified files" sections
+const TOP_FILES: usize = 10;
+
 /// Write statistics about the analyzed files
 fn write_statistics(writer: &mut impl Write, stats: &FileStats) -> Result<()> {
     writeln!(writer, "Project Statistics").context("Failed to write statistics header")?;
@@ -328,5 +730,76 @@ fn write_statistics(writer: &mut impl Write, stats: &FileStats) -> Result<()> {
         }
     }
 
+    if !stats.directory_sizes.is_empty() {
+        writeln!(writer, "\nLargest directories:").context("Failed to write statistics")?;
+
+        for (path, lines, bytes) in stats.directory_sizes.iter().take(TOP_DIRECTORIES) {
+            writeln!(
+                writer,
+                "  {}/: {} lines, {}",
+                path.display(),
+                lines,
+                format_bytes(*bytes)
+            )
+            .context("Failed to write statistics")?;
+        }
+    }
+
+    if !stats.file_metadata.is_empty() {
+        let mut by_size: Vec<&FileMetadata> = stats.file_metadata.iter().collect();
+        by_size.sort_by(|a, b| b.size.cmp(&a.size));
+
+        writeln!(writer, "\nLargest files:").context("Failed to write statistics")?;
+        for entry in by_size.iter().take(TOP_FILES) {
+            writeln!(writer, "  {}: {}", entry.path.display(), format_bytes(entry.size))
+                .context("Failed to write statistics")?;
+        }
+
+        let mut by_mtime: Vec<&FileMetadata> = stats.file_metadata.iter().collect();
+        by_mtime.sort_by(|a, b| b.modified_unix_secs.cmp(&a.modified_unix_secs));
+
+        writeln!(writer, "\nRecently modified files:").context("Failed to write statistics")?;
+        for entry in by_mtime.iter().take(TOP_FILES) {
+            writeln!(
+                writer,
+                "  {}: {} (unix time)",
+                entry.path.display(),
+                entry.modified_unix_secs
+            )
+            .context("Failed to write statistics")?;
+        }
+    }
+
+    if !stats.duplicates.is_empty() {
+        writeln!(writer, "\nDuplicate files:").context("Failed to write statistics")?;
+
+        let mut total_reclaimable: u64 = 0;
+
+        for group in &stats.duplicates {
+            let group_size = group
+                .first()
+                .and_then(|path| std::fs::metadata(path).ok())
+                .map(|metadata| metadata.len())
+                .unwrap_or(0);
+            let reclaimable = group_size * (group.len() as u64 - 1);
+            total_reclaimable += reclaimable;
+
+            writeln!(
+                writer,
+                "  {} bytes x {} copies ({} bytes reclaimable):",
+                group_size,
+                group.len(),
+                reclaimable
+            )
+            .context("Failed to write statistics")?;
+            for path in group {
+                writeln!(writer, "    {}", path.display()).context("Failed to write statistics")?;
+            }
+        }
+
+        writeln!(writer, "  Total reclaimable space: {} bytes", total_reclaimable)
+            .context("Failed to write statistics")?;
+    }
+
     Ok(())
 }